@@ -12,15 +12,134 @@ persists and is thread safe.
 extern crate rocket;
 #[macro_use]
 extern crate rocket_okapi;
+#[macro_use]
+extern crate diesel;
+#[macro_use]
+extern crate diesel_migrations;
+extern crate argon2;
+extern crate rand;
+
+mod schema;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
 
-use std::sync::{Mutex};
+use diesel::prelude::*;
+use log::{error, info};
+use rand::Rng;
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::http::Status;
+use rocket::request::{self, FromRequest, Request};
+use rocket::Data;
+use rocket::Outcome;
+use rocket::Response;
 use rocket::Rocket;
 use rocket::State;
+use rocket_contrib::databases::database;
 use rocket_contrib::json::Json;
 use rocket_okapi::swagger_ui::{make_swagger_ui, SwaggerUIConfig};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use schema::logger_state;
+use schema::users;
+
+embed_migrations!("migrations");
+
+#[database("logger_db")]
+struct DbConn(diesel::SqliteConnection);
+
+/// Bearer tokens issued by `/login`, mapped to the username they authenticate.
+type TokenStore = Mutex<HashMap<String, String>>;
+
+/// Total number of requests handled since startup, incremented by `RequestTimer`.
+type RequestCounter = AtomicUsize;
+
+/// Fairing that logs each request's method, URI, status, and latency, and
+/// bumps the global request counter exposed by `GET /metrics`.
+struct RequestTimer;
+
+impl Fairing for RequestTimer {
+    fn info(&self) -> Info {
+        Info {
+            name: "Request Timer",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    fn on_request(&self, request: &mut Request, _: &Data) {
+        request.local_cache(Instant::now);
+        if let Outcome::Success(counter) = request.guard::<State<RequestCounter>>() {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn on_response(&self, request: &Request, response: &mut Response) {
+        let start = request.local_cache(Instant::now);
+        let elapsed_ms = start.elapsed().as_millis();
+        info!(
+            "{} {} -> {} ({} ms)",
+            request.method(),
+            request.uri(),
+            response.status(),
+            elapsed_ms
+        );
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct MetricsResponse {
+    request_count: usize,
+}
+
+#[openapi]
+#[get("/metrics", format = "json")]
+fn metrics(counter: State<RequestCounter>) -> Json<MetricsResponse> {
+    Json(MetricsResponse {
+        request_count: counter.load(Ordering::Relaxed),
+    })
+}
+
+/// A single entry in the append-only commit log, addressable by its
+/// monotonically increasing `offset`.
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+struct Record {
+    value: String,
+    offset: u64,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AppendRequest {
+    value: String,
+}
+
+/// An append-only log of records, each addressable by its offset.
+type CommitLog = Mutex<Vec<Record>>;
+
+#[openapi]
+#[post("/log", format = "json", data = "<req>")]
+fn append(req: Json<AppendRequest>, log: State<CommitLog>) -> Json<Record> {
+    let mut log = log.lock().unwrap();
+    let record = Record {
+        value: req.value.clone(),
+        offset: log.len() as u64,
+    };
+    log.push(record.clone());
+    Json(record)
+}
+
+#[openapi]
+#[get("/log/<offset>")]
+fn read(offset: u64, log: State<CommitLog>) -> Option<Json<Record>> {
+    let log = log.lock().unwrap();
+    log.get(offset as usize).cloned().map(Json)
+}
+
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 struct StartRequest {
@@ -30,6 +149,7 @@ struct StartRequest {
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 struct LoggingResponse {
+    id: i64,
     path: Option<String>,
     previous_path: Option<String>,
     active: bool,
@@ -37,94 +157,364 @@ struct LoggingResponse {
     request_message: Option<String>,
 }
 
+/// A JSON-first error envelope, returned by the error catchers below so
+/// clients never have to parse Rocket's default HTML error pages.
+#[derive(Serialize, JsonSchema)]
+struct ErrorResponse {
+    status: String,
+    code: u16,
+    reason: String,
+}
+
+impl ErrorResponse {
+    fn new(code: u16, reason: &str) -> ErrorResponse {
+        ErrorResponse {
+            status: "error".to_string(),
+            code,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+#[catch(404)]
+fn not_found() -> Json<ErrorResponse> {
+    Json(ErrorResponse::new(404, "Resource not found"))
+}
+
+#[catch(422)]
+fn unprocessable_entity() -> Json<ErrorResponse> {
+    Json(ErrorResponse::new(422, "Malformed or invalid request body"))
+}
+
+#[catch(500)]
+fn internal_error() -> Json<ErrorResponse> {
+    Json(ErrorResponse::new(500, "Internal server error"))
+}
+
+/// `#[catch]` handlers aren't scanned by `rocket_okapi`, so `ErrorResponse`
+/// would otherwise never reach `openapi.json`. This route exists purely to
+/// put a real `#[openapi]`-annotated handler on the schema, documenting the
+/// shape every catcher above actually returns.
+#[openapi]
+#[get("/errors/example", format = "json")]
+fn error_example() -> Json<ErrorResponse> {
+    Json(ErrorResponse::new(500, "Example error shape returned by the catchers"))
+}
+
+impl LoggingResponse {
+    fn from_state(state: &LoggerState, status: bool, message: Option<String>) -> LoggingResponse {
+        LoggingResponse {
+            id: state.id,
+            path: state.path.clone(),
+            previous_path: state.previous_path.clone(),
+            active: state.active,
+            request_status: status,
+            request_message: message,
+        }
+    }
+}
+
+/// Fetches the row for `session_id`, inserting a fresh inactive session
+/// first if one does not exist yet.
+fn get_or_create(conn: &SqliteConnection, session_id: i64) -> LoggerState {
+    use schema::logger_state::dsl::*;
+    match logger_state
+        .find(session_id)
+        .first::<LoggerState>(conn)
+        .optional()
+        .unwrap()
+    {
+        Some(row) => row,
+        None => {
+            let row = LoggerState::new(session_id);
+            diesel::insert_into(logger_state)
+                .values(&row)
+                .execute(conn)
+                .unwrap();
+            row
+        }
+    }
+}
+
+fn save(conn: &SqliteConnection, state: &LoggerState) {
+    use schema::logger_state::dsl::*;
+    diesel::update(logger_state.find(state.id))
+        .set((
+            path.eq(&state.path),
+            previous_path.eq(&state.previous_path),
+            call_count.eq(state.call_count),
+            active.eq(state.active),
+        ))
+        .execute(conn)
+        .unwrap();
+}
+
+/// Runs `f` inside a `BEGIN IMMEDIATE` transaction, so the write lock is
+/// acquired up front instead of Diesel's default deferred `BEGIN`. Without
+/// this, two requests racing on the same session id can both read the row
+/// before either writes it back, silently dropping one side's update.
+fn with_immediate_transaction<T>(conn: &SqliteConnection, f: impl FnOnce() -> T) -> T {
+    diesel::sql_query("BEGIN IMMEDIATE")
+        .execute(conn)
+        .expect("failed to acquire immediate write lock");
+    let result = f();
+    diesel::sql_query("COMMIT")
+        .execute(conn)
+        .expect("failed to commit transaction");
+    result
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+struct Credentials {
+    username: String,
+    password: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AuthResponse {
+    status: bool,
+    message: String,
+    token: Option<String>,
+}
+
+#[derive(Debug, Queryable, Insertable, Clone)]
+#[table_name = "users"]
+struct User {
+    username: String,
+    password_salt: String,
+    password_hash: String,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[openapi]
+#[post("/register", format = "json", data = "<creds>")]
+fn register(creds: Json<Credentials>, conn: DbConn) -> Json<AuthResponse> {
+    use diesel::result::{DatabaseErrorKind, Error};
+    use schema::users::dsl;
+    let salt: [u8; 16] = rand::thread_rng().gen();
+    let hash = argon2::hash_encoded(creds.password.as_bytes(), &salt, &argon2::Config::default())
+        .unwrap();
+    let user = User {
+        username: creds.username.clone(),
+        password_salt: to_hex(&salt),
+        password_hash: hash,
+    };
+    // Let the `users.username` primary key catch the race instead of a
+    // check-then-insert, so two concurrent registrations for the same
+    // name can't both pass a pre-insert existence check.
+    let result = conn.transaction::<_, Error, _>(|| {
+        diesel::insert_into(dsl::users).values(&user).execute(&*conn)
+    });
+    match result {
+        Ok(_) => Json(AuthResponse {
+            status: true,
+            message: "User registered".to_string(),
+            token: None,
+        }),
+        Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => Json(AuthResponse {
+            status: false,
+            message: "Username already registered".to_string(),
+            token: None,
+        }),
+        Err(e) => panic!("Failed to register user: {:?}", e),
+    }
+}
+
+#[openapi]
+#[post("/login", format = "json", data = "<creds>")]
+fn login(creds: Json<Credentials>, conn: DbConn, tokens: State<TokenStore>) -> Json<AuthResponse> {
+    use schema::users::dsl;
+    let user = dsl::users
+        .find(&creds.username)
+        .first::<User>(&*conn)
+        .optional()
+        .unwrap();
+    let verified = user
+        .as_ref()
+        .map(|user| argon2::verify_encoded(&user.password_hash, creds.password.as_bytes()).unwrap_or(false))
+        .unwrap_or(false);
+    if !verified {
+        return Json(AuthResponse {
+            status: false,
+            message: "Invalid username or password".to_string(),
+            token: None,
+        });
+    }
+    let token = to_hex(&rand::thread_rng().gen::<[u8; 16]>());
+    tokens
+        .lock()
+        .unwrap()
+        .insert(token.clone(), creds.username.clone());
+    Json(AuthResponse {
+        status: true,
+        message: "Login successful".to_string(),
+        token: Some(token),
+    })
+}
+
+/// Request guard requiring a valid `Authorization: Bearer <token>` header,
+/// issued previously by `/login`.
+struct AuthUser {
+    #[allow(dead_code)]
+    username: String,
+}
+
+#[derive(Debug)]
+enum AuthError {
+    Missing,
+    Invalid,
+}
+
+impl<'a, 'r> FromRequest<'a, 'r> for AuthUser {
+    type Error = AuthError;
+
+    fn from_request(request: &'a Request<'r>) -> request::Outcome<Self, Self::Error> {
+        let tokens = match request.guard::<State<TokenStore>>() {
+            Outcome::Success(tokens) => tokens,
+            _ => return Outcome::Failure((Status::Unauthorized, AuthError::Missing)),
+        };
+        let token = request
+            .headers()
+            .get_one("Authorization")
+            .and_then(|header| header.strip_prefix("Bearer "));
+        match token {
+            Some(token) => match tokens.lock().unwrap().get(token) {
+                Some(username) => Outcome::Success(AuthUser {
+                    username: username.clone(),
+                }),
+                None => Outcome::Failure((Status::Unauthorized, AuthError::Invalid)),
+            },
+            None => Outcome::Failure((Status::Unauthorized, AuthError::Missing)),
+        }
+    }
+}
+
+#[catch(401)]
+fn unauthorized() -> Json<ErrorResponse> {
+    Json(ErrorResponse::new(401, "Missing or invalid authorization token"))
+}
+
 // PUT is idempotent, repeated calls return same value
 
 #[openapi]
-#[post("/start", format = "json", data = "<req>")]
-fn start(req: Json<StartRequest>, db: State<Db>) -> Json<LoggingResponse> {
-    let mut db = db.lock().unwrap();
-    let mut status = true;
-    let mut message = Some("Logging started".to_string());
-    db.call_count += 1;
-    if Some(req.path.clone()) == db.path && db.active {
-        status = false;
-        message = Some("Already logging to this path".to_string());
-    } else {
-        db.active = true;
-        if ! db.path.is_none() {
-            db.previous_path = db.path.clone();
+#[post("/logging/<id>/start", format = "json", data = "<req>")]
+fn start(id: i64, req: Json<StartRequest>, conn: DbConn, _user: AuthUser) -> Json<LoggingResponse> {
+    with_immediate_transaction(&conn, || {
+        let mut state = get_or_create(&conn, id);
+        let mut status = true;
+        let mut message = Some("Logging started".to_string());
+        state.call_count += 1;
+        if Some(req.path.clone()) == state.path && state.active {
+            status = false;
+            message = Some("Already logging to this path".to_string());
+        } else {
+            state.active = true;
+            if !state.path.is_none() {
+                state.previous_path = state.path.clone();
+            }
+            state.path = Some(req.path.clone());
         }
-        db.path = Some(req.path.clone());
-    }
-    Json(LoggingResponse {
-        path: db.path.clone(),
-        previous_path: db.previous_path.clone(),
-        active: db.active,
-        request_status: status,
-        request_message: message,
+        save(&conn, &state);
+        Json(LoggingResponse::from_state(&state, status, message))
     })
 }
 
 #[openapi]
-#[post("/stop")]
-fn stop(db: State<Db>) -> Json<LoggingResponse> {
-    let mut db = db.lock().unwrap();
-    let mut status = true;
-    let mut message = Some("Logging stopped".to_string());
-    db.call_count += 1;
-    if ! db.active {
-        status = false;
-        message = Some("No logging was active".to_string());
-    } else {
-        db.active = true;
-        db.previous_path = db.path.clone();
-        db.path = None;
-        db.active = false;
-    }
-    Json(LoggingResponse {
-        path: db.path.clone(),
-        previous_path: db.previous_path.clone(),
-        active: db.active,
-        request_status: status,
-        request_message: message,
+#[post("/logging/<id>/stop")]
+fn stop(id: i64, conn: DbConn, _user: AuthUser) -> Json<LoggingResponse> {
+    with_immediate_transaction(&conn, || {
+        let mut state = get_or_create(&conn, id);
+        let mut status = true;
+        let mut message = Some("Logging stopped".to_string());
+        state.call_count += 1;
+        if !state.active {
+            status = false;
+            message = Some("No logging was active".to_string());
+        } else {
+            state.previous_path = state.path.clone();
+            state.path = None;
+            state.active = false;
+        }
+        save(&conn, &state);
+        Json(LoggingResponse::from_state(&state, status, message))
     })
 }
 
 #[openapi]
-#[get("/status", format = "json")]
-fn status(db: State<Db>) -> Json<LoggingResponse> {
-    let mut db = db.lock().unwrap();
-    let message = if db.active {
-        Some("Logging active".to_string())
-    } else {
-        Some("No logging active".to_string())
-    };
-    db.call_count += 1;
-    Json(LoggingResponse {
-        path: db.path.clone(),
-        previous_path: db.previous_path.clone(),
-        active: db.active,
-        request_status: true,
-        request_message: message,
+#[get("/logging/<id>/status", format = "json")]
+fn status(id: i64, conn: DbConn) -> Json<LoggingResponse> {
+    with_immediate_transaction(&conn, || {
+        let mut state = get_or_create(&conn, id);
+        let message = if state.active {
+            Some("Logging active".to_string())
+        } else {
+            Some("No logging active".to_string())
+        };
+        state.call_count += 1;
+        save(&conn, &state);
+        Json(LoggingResponse::from_state(&state, true, message))
     })
 }
 
-/// A simple in-memory DB to store logging state
-type Db = Mutex<LoggerState>;
+#[openapi]
+#[delete("/logging/<id>")]
+fn delete(id: i64, conn: DbConn, _user: AuthUser) -> Json<LoggingResponse> {
+    use schema::logger_state::dsl;
+    match dsl::logger_state.find(id).first::<LoggerState>(&*conn).optional().unwrap() {
+        Some(state) => {
+            diesel::delete(dsl::logger_state.find(id))
+                .execute(&*conn)
+                .unwrap();
+            Json(LoggingResponse::from_state(
+                &state,
+                true,
+                Some("Session deleted".to_string()),
+            ))
+        }
+        None => Json(LoggingResponse::from_state(
+            &LoggerState::new(id),
+            false,
+            Some("No such session".to_string()),
+        )),
+    }
+}
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+// Read-only, so like `status` it stays public; `delete` mutates state and
+// requires `AuthUser` above.
+#[openapi]
+#[get("/logging", format = "json")]
+fn list(conn: DbConn) -> Json<Vec<LoggingResponse>> {
+    use schema::logger_state::dsl;
+    let rows = dsl::logger_state
+        .order(dsl::id.asc())
+        .load::<LoggerState>(&*conn)
+        .unwrap();
+    let sessions = rows
+        .iter()
+        .map(|state| LoggingResponse::from_state(state, true, None))
+        .collect();
+    Json(sessions)
+}
+
+#[derive(Debug, Queryable, Insertable, Deserialize, Serialize, Clone)]
+#[table_name = "logger_state"]
 struct LoggerState {
-    pub id: u64,
+    pub id: i64,
     pub path: Option<String>,
     pub previous_path: Option<String>,
-    pub call_count: u32,
+    pub call_count: i32,
     pub active: bool,
 }
 
 impl LoggerState {
-    fn new() -> LoggerState {
-        LoggerState{
-            id: 0,
+    fn new(id: i64) -> LoggerState {
+        LoggerState {
+            id,
             path: None,
             previous_path: None,
             call_count: 0,
@@ -135,8 +525,34 @@ impl LoggerState {
 
 fn build_app() -> Rocket {
     rocket::ignite()
-        .manage(Mutex::new(LoggerState::new()))
-        .mount("/", routes_with_openapi![status, start, stop])
+        .attach(DbConn::fairing())
+        .attach(rocket::fairing::AdHoc::on_attach("Database Migrations", |rocket| {
+            let conn = DbConn::get_one(&rocket).expect("database connection for migrations");
+            match embedded_migrations::run(&*conn) {
+                Ok(()) => Ok(rocket),
+                Err(e) => {
+                    error!("Failed to run database migrations: {:?}", e);
+                    Err(rocket)
+                }
+            }
+        }))
+        .attach(RequestTimer)
+        .manage(Mutex::new(HashMap::<String, String>::new()))
+        .manage(RequestCounter::new(0))
+        .manage(Mutex::new(Vec::<Record>::new()))
+        .register(catchers![
+            unauthorized,
+            not_found,
+            unprocessable_entity,
+            internal_error
+        ])
+        .mount(
+            "/",
+            routes_with_openapi![
+                status, start, stop, delete, list, register, login, metrics, append, read,
+                error_example
+            ],
+        )
         .mount(
             "/docs/",
             make_swagger_ui(&SwaggerUIConfig {
@@ -153,21 +569,213 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::build_app;
-    use rocket::http::{ContentType, Status};
+    use rocket::http::{ContentType, Header, Status};
     use rocket::local::Client;
 
     #[test]
     fn status() {
         let client = Client::new(build_app()).expect("Could not build app");
-        let req = client
-            .post("/logging/status")
-            .header(ContentType::JSON)
-            .body(r#"{"path": "/a/b", "action": "start"}"#);
+        let req = client.get("/logging/9001/status").header(ContentType::JSON);
         let mut resp = req.dispatch();
         assert_eq!(resp.status(), Status::Ok);
-        assert_eq!(
-            resp.body_string(),
-            Some(r#"{"name":"Bob","id":null}"#.to_string())
-        );
+        let body = resp.body_string().expect("response body");
+        assert!(body.contains("\"id\":9001"));
+        assert!(body.contains("\"active\":false"));
+    }
+
+    #[test]
+    fn register_rejects_duplicate_username() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        let creds = r#"{"username": "test-register-dup", "password": "hunter2"}"#;
+
+        let mut first = client
+            .post("/register")
+            .header(ContentType::JSON)
+            .body(creds)
+            .dispatch();
+        assert_eq!(first.status(), Status::Ok);
+        assert!(first.body_string().unwrap().contains("\"status\":true"));
+
+        let mut second = client
+            .post("/register")
+            .header(ContentType::JSON)
+            .body(creds)
+            .dispatch();
+        assert_eq!(second.status(), Status::Ok);
+        assert!(second.body_string().unwrap().contains("\"status\":false"));
+    }
+
+    #[test]
+    fn login_issues_token_for_correct_password() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        let creds = r#"{"username": "test-login-ok", "password": "hunter2"}"#;
+        client
+            .post("/register")
+            .header(ContentType::JSON)
+            .body(creds)
+            .dispatch();
+
+        let mut resp = client
+            .post("/login")
+            .header(ContentType::JSON)
+            .body(creds)
+            .dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        let body = resp.body_string().unwrap();
+        assert!(body.contains("\"status\":true"));
+        assert!(body.contains("\"token\":\""));
+    }
+
+    #[test]
+    fn login_rejects_wrong_password() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        client
+            .post("/register")
+            .header(ContentType::JSON)
+            .body(r#"{"username": "test-login-bad", "password": "hunter2"}"#)
+            .dispatch();
+
+        let mut resp = client
+            .post("/login")
+            .header(ContentType::JSON)
+            .body(r#"{"username": "test-login-bad", "password": "wrong"}"#)
+            .dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        let body = resp.body_string().unwrap();
+        assert!(body.contains("\"status\":false"));
+        assert!(body.contains("\"token\":null"));
+    }
+
+    #[test]
+    fn start_requires_auth() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        let resp = client
+            .post("/logging/9002/start")
+            .header(ContentType::JSON)
+            .body(r#"{"path": "/a/b"}"#)
+            .dispatch();
+        assert_eq!(resp.status(), Status::Unauthorized);
+    }
+
+    #[test]
+    fn start_succeeds_with_valid_token() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        let creds = r#"{"username": "test-start-auth", "password": "hunter2"}"#;
+        client
+            .post("/register")
+            .header(ContentType::JSON)
+            .body(creds)
+            .dispatch();
+        let mut login_resp = client
+            .post("/login")
+            .header(ContentType::JSON)
+            .body(creds)
+            .dispatch();
+        let login_body = login_resp.body_string().unwrap();
+        let token_start = login_body.find("\"token\":\"").unwrap() + "\"token\":\"".len();
+        let token = &login_body[token_start..login_body[token_start..].find('"').unwrap() + token_start];
+
+        let resp = client
+            .post("/logging/9003/start")
+            .header(ContentType::JSON)
+            .header(Header::new("Authorization", format!("Bearer {}", token)))
+            .body(r#"{"path": "/a/b"}"#)
+            .dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+    }
+
+    #[test]
+    fn list_includes_known_sessions() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        client.get("/logging/9101/status").dispatch();
+
+        let mut resp = client.get("/logging").dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        assert!(resp.body_string().unwrap().contains("\"id\":9101"));
+    }
+
+    #[test]
+    fn delete_requires_auth() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        client.get("/logging/9102/status").dispatch();
+
+        let resp = client.delete("/logging/9102").dispatch();
+        assert_eq!(resp.status(), Status::Unauthorized);
+    }
+
+    #[test]
+    fn delete_removes_known_session() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        client.get("/logging/9103/status").dispatch();
+
+        let creds = r#"{"username": "test-delete-session", "password": "hunter2"}"#;
+        client
+            .post("/register")
+            .header(ContentType::JSON)
+            .body(creds)
+            .dispatch();
+        let mut login_resp = client
+            .post("/login")
+            .header(ContentType::JSON)
+            .body(creds)
+            .dispatch();
+        let login_body = login_resp.body_string().unwrap();
+        let token_start = login_body.find("\"token\":\"").unwrap() + "\"token\":\"".len();
+        let token = &login_body[token_start..login_body[token_start..].find('"').unwrap() + token_start];
+
+        let mut resp = client
+            .delete("/logging/9103")
+            .header(Header::new("Authorization", format!("Bearer {}", token)))
+            .dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        assert!(resp.body_string().unwrap().contains("\"requestStatus\":true"));
+    }
+
+    fn extract_offset(body: &str) -> String {
+        let start = body.find("\"offset\":").unwrap() + "\"offset\":".len();
+        body[start..].chars().take_while(|c| c.is_ascii_digit()).collect()
+    }
+
+    #[test]
+    fn append_assigns_sequential_offsets() {
+        let client = Client::new(build_app()).expect("Could not build app");
+
+        let mut first = client
+            .post("/log")
+            .header(ContentType::JSON)
+            .body(r#"{"value": "first"}"#)
+            .dispatch();
+        assert_eq!(first.status(), Status::Ok);
+        let first_offset: u64 = extract_offset(&first.body_string().unwrap()).parse().unwrap();
+
+        let mut second = client
+            .post("/log")
+            .header(ContentType::JSON)
+            .body(r#"{"value": "second"}"#)
+            .dispatch();
+        let second_body = second.body_string().unwrap();
+        assert!(second_body.contains(&format!("\"offset\":{}", first_offset + 1)));
+    }
+
+    #[test]
+    fn read_returns_record_at_offset() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        let mut append_resp = client
+            .post("/log")
+            .header(ContentType::JSON)
+            .body(r#"{"value": "round-trip"}"#)
+            .dispatch();
+        let offset = extract_offset(&append_resp.body_string().unwrap());
+
+        let mut resp = client.get(format!("/log/{}", offset)).dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        assert!(resp.body_string().unwrap().contains("round-trip"));
+    }
+
+    #[test]
+    fn read_returns_404_for_out_of_range_offset() {
+        let client = Client::new(build_app()).expect("Could not build app");
+        let resp = client.get("/log/999999999").dispatch();
+        assert_eq!(resp.status(), Status::NotFound);
     }
 }
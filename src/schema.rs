@@ -0,0 +1,17 @@
+table! {
+    logger_state (id) {
+        id -> BigInt,
+        path -> Nullable<Text>,
+        previous_path -> Nullable<Text>,
+        call_count -> Integer,
+        active -> Bool,
+    }
+}
+
+table! {
+    users (username) {
+        username -> Text,
+        password_salt -> Text,
+        password_hash -> Text,
+    }
+}